@@ -21,19 +21,20 @@ impl Chip8 {
                 program_counter: ROM_START as u16,
                 index_register: 0,
                 registers: [0; 16],
+                keypad: [false; 16],
             },
             display: Display::new(),
             memory,
         }
     }
 
-    pub fn advance(&mut self) {
+    pub fn advance(&mut self, keys: &[bool; 16]) {
         let Chip8 {
             cpu,
             display,
             memory,
         } = self;
-        advance(cpu, display, memory);
+        advance(cpu, display, memory, keys);
     }
 
     pub fn display(&self) -> &Display {
@@ -41,7 +42,7 @@ impl Chip8 {
     }
 }
 
-pub fn advance(cpu: &mut CPU, display: &mut Display, memory: &mut Memory) {
+pub fn advance(cpu: &mut CPU, display: &mut Display, memory: &mut Memory, keys: &[bool; 16]) {
     let instr = [
         memory.get(cpu.program_counter),
         memory.get(cpu.program_counter + 1),
@@ -214,7 +215,20 @@ pub fn advance(cpu: &mut CPU, display: &mut Display, memory: &mut Memory) {
         }
         0xE => {
             // skip based on input
-            // TODO: keyboard input
+            let vx = cpu.register((instr & 0x0F00) >> 8) & 0x0F;
+            match instr & 0x00FF {
+                0x9E => {
+                    if keys[vx as usize] {
+                        cpu.program_counter += 2;
+                    }
+                }
+                0xA1 => {
+                    if !keys[vx as usize] {
+                        cpu.program_counter += 2;
+                    }
+                }
+                _ => panic!("instruction unknown: {}", instr),
+            }
         }
         0xF => {
             let op = (instr & 0x0F00) >> 8;
@@ -236,7 +250,16 @@ pub fn advance(cpu: &mut CPU, display: &mut Display, memory: &mut Memory) {
                     cpu.index_register = result;
                 }
                 0x0A => {
-                    // TODO: block and wait for key input
+                    // block until a key that was down last frame is released
+                    let released = (0..16).find(|&i| cpu.keypad[i] && !keys[i]);
+                    match released {
+                        Some(i) => {
+                            cpu.set_register(op, i as u8);
+                        }
+                        None => {
+                            cpu.program_counter -= 2;
+                        }
+                    }
                 }
                 0x29 => {
                     cpu.index_register = (FONT_START as u16) + (cpu.register(op) as u16) & 0x0F * 5;
@@ -264,6 +287,8 @@ pub fn advance(cpu: &mut CPU, display: &mut Display, memory: &mut Memory) {
         }
         _ => unreachable!("Cannot have a nibble higher than 0xF"),
     }
+
+    cpu.keypad = *keys;
 }
 
 pub struct CPU {
@@ -273,6 +298,7 @@ pub struct CPU {
     pub program_counter: u16,
     pub index_register: u16,
     pub registers: [u8; 16],
+    pub keypad: [bool; 16],
 }
 
 impl CPU {